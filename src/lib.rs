@@ -0,0 +1,693 @@
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt;
+use std::fs;
+use std::io::{self, BufRead};
+use std::path::Path;
+extern crate serde;
+extern crate serde_derive;
+use serde::{Serialize, Deserialize};
+
+/// Error returned by the fallible parts of the matcher — construction,
+/// (de)serialization of model artifacts, and file access.
+#[derive(Debug)]
+pub enum PhraseMatcherError {
+    Io(io::Error),
+    Bincode(bincode::Error),
+    Fst(fst::Error),
+    /// A saved model was requested but no vocabulary artifact was found in
+    /// `model_dir` for the configured backend.
+    MissingVocab,
+}
+
+impl fmt::Display for PhraseMatcherError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PhraseMatcherError::Io(e) => write!(f, "io error: {}", e),
+            PhraseMatcherError::Bincode(e) => write!(f, "bincode error: {}", e),
+            PhraseMatcherError::Fst(e) => write!(f, "fst error: {}", e),
+            PhraseMatcherError::MissingVocab => write!(f, "no vocabulary found in model_dir"),
+        }
+    }
+}
+
+impl std::error::Error for PhraseMatcherError {}
+
+impl From<io::Error> for PhraseMatcherError {
+    fn from(e: io::Error) -> Self {
+        PhraseMatcherError::Io(e)
+    }
+}
+
+impl From<bincode::Error> for PhraseMatcherError {
+    fn from(e: bincode::Error) -> Self {
+        PhraseMatcherError::Bincode(e)
+    }
+}
+
+impl From<fst::Error> for PhraseMatcherError {
+    fn from(e: fst::Error) -> Self {
+        PhraseMatcherError::Fst(e)
+    }
+}
+
+/// Strategy for splitting raw text into the token strings the matcher keys on.
+///
+/// Whitespace splitting only works for languages with explicit word
+/// boundaries; the lattice backend below handles scripts (Japanese, Chinese)
+/// and morphologically rich input where `split_whitespace` would collapse a
+/// whole sentence into one token.
+pub trait Tokenizer {
+    fn tokenize(&self, text: &str) -> Vec<String>;
+}
+
+/// Lowercase-free whitespace tokenizer, matching the crate's original
+/// behaviour. Callers that want case folding lowercase the input first.
+pub struct WhitespaceTokenizer;
+
+impl Tokenizer for WhitespaceTokenizer {
+    fn tokenize(&self, text: &str) -> Vec<String> {
+        text.split_whitespace().map(|s| s.to_string()).collect()
+    }
+}
+
+/// Word cost charged to a node covering an out-of-dictionary character run.
+const UNKNOWN_WORD_COST: i64 = 3000;
+
+/// A dictionary reading with the MeCab-style costs used to score it in the
+/// lattice. `left_id`/`right_id` index the connection-cost matrix.
+struct DictEntry {
+    left_id: usize,
+    right_id: usize,
+    word_cost: i64,
+}
+
+/// One lattice node: a candidate span `[start, end)` over the input chars.
+struct LatticeNode {
+    start: usize,
+    end: usize,
+    surface: String,
+    left_id: usize,
+    right_id: usize,
+    word_cost: i64,
+}
+
+/// Dictionary-driven lattice tokenizer performing Viterbi shortest-path
+/// segmentation. Every dictionary term that starts at a position becomes a
+/// node; positions with no dictionary term get an unknown node spanning the
+/// run of same-class characters up to (not including) the next position where
+/// a dictionary term begins, so the lattice always reaches the end of the
+/// input. The path minimising `word_cost + connection_cost` over
+/// adjacent nodes is the chosen segmentation.
+pub struct LatticeTokenizer {
+    dict: HashMap<String, Vec<DictEntry>>,
+    connection: HashMap<(usize, usize), i64>,
+}
+
+impl LatticeTokenizer {
+    /// Load a comma-separated term dictionary (`surface,left_id,right_id,cost`)
+    /// and a MeCab-style connection matrix whose first line is the dimensions
+    /// followed by `left right cost` rows.
+    pub fn load(dict_path: &str, matrix_path: &str) -> Result<Self, PhraseMatcherError> {
+        let mut dict: HashMap<String, Vec<DictEntry>> = HashMap::new();
+        let reader = io::BufReader::new(fs::File::open(dict_path)?);
+        for line in reader.lines() {
+            let line = line?;
+            let f: Vec<&str> = line.trim().split(',').collect();
+            if f.len() < 4 {
+                continue;
+            }
+            let entry = DictEntry {
+                left_id: f[1].parse().unwrap_or(0),
+                right_id: f[2].parse().unwrap_or(0),
+                word_cost: f[3].parse().unwrap_or(0),
+            };
+            dict.entry(f[0].to_string()).or_default().push(entry);
+        }
+
+        let mut connection = HashMap::new();
+        let reader = io::BufReader::new(fs::File::open(matrix_path)?);
+        for (i, line) in reader.lines().enumerate() {
+            let line = line?;
+            if i == 0 {
+                continue; // dimensions header
+            }
+            let f: Vec<&str> = line.split_whitespace().collect();
+            if f.len() < 3 {
+                continue;
+            }
+            let left = f[0].parse().unwrap_or(0);
+            let right = f[1].parse().unwrap_or(0);
+            connection.insert((left, right), f[2].parse().unwrap_or(0));
+        }
+
+        Ok(LatticeTokenizer { dict, connection })
+    }
+
+    fn connection_cost(&self, right_id: usize, left_id: usize) -> i64 {
+        self.connection.get(&(right_id, left_id)).copied().unwrap_or(0)
+    }
+
+    /// Whether any dictionary term begins exactly at `pos`, used to stop an
+    /// unknown run from swallowing a word that should be segmented on its own.
+    fn dict_starts(&self, chars: &[char], pos: usize) -> bool {
+        let mut surface = String::new();
+        for &c in &chars[pos..] {
+            surface.push(c);
+            if self.dict.contains_key(&surface) {
+                return true;
+            }
+        }
+        false
+    }
+}
+
+fn char_class(c: char) -> u8 {
+    if c.is_whitespace() {
+        0
+    } else if c.is_numeric() {
+        1
+    } else if c.is_alphabetic() {
+        2
+    } else {
+        3
+    }
+}
+
+impl Tokenizer for LatticeTokenizer {
+    fn tokenize(&self, text: &str) -> Vec<String> {
+        let chars: Vec<char> = text.chars().collect();
+        let n = chars.len();
+        if n == 0 {
+            return Vec::new();
+        }
+
+        // Node 0 is the BOS sentinel ending at position 0.
+        let mut nodes = vec![LatticeNode {
+            start: 0,
+            end: 0,
+            surface: String::new(),
+            left_id: 0,
+            right_id: 0,
+            word_cost: 0,
+        }];
+
+        for s in 0..n {
+            let mut matched = false;
+            let mut surface = String::new();
+            for len in 1..=(n - s) {
+                surface.push(chars[s + len - 1]);
+                if let Some(entries) = self.dict.get(&surface) {
+                    matched = true;
+                    for e in entries {
+                        nodes.push(LatticeNode {
+                            start: s,
+                            end: s + len,
+                            surface: surface.clone(),
+                            left_id: e.left_id,
+                            right_id: e.right_id,
+                            word_cost: e.word_cost,
+                        });
+                    }
+                }
+            }
+
+            if !matched {
+                let class = char_class(chars[s]);
+                let mut e = s + 1;
+                while e < n && char_class(chars[e]) == class && !self.dict_starts(&chars, e) {
+                    e += 1;
+                }
+                nodes.push(LatticeNode {
+                    start: s,
+                    end: e,
+                    surface: chars[s..e].iter().collect(),
+                    left_id: 0,
+                    right_id: 0,
+                    word_cost: UNKNOWN_WORD_COST,
+                });
+            }
+        }
+
+        let eos = nodes.len();
+        nodes.push(LatticeNode {
+            start: n,
+            end: n,
+            surface: String::new(),
+            left_id: 0,
+            right_id: 0,
+            word_cost: 0,
+        });
+
+        // Bucket node indices by the position at which they end so a node can
+        // find every predecessor it may connect to.
+        let mut ends: Vec<Vec<usize>> = vec![Vec::new(); n + 1];
+        ends[0].push(0);
+        for (idx, node) in nodes.iter().enumerate().skip(1) {
+            ends[node.end].push(idx);
+        }
+
+        let mut best = vec![i64::MAX; nodes.len()];
+        let mut back = vec![usize::MAX; nodes.len()];
+        best[0] = 0;
+
+        let mut order: Vec<usize> = (1..nodes.len()).collect();
+        order.sort_by_key(|&i| nodes[i].start);
+        for &i in &order {
+            let (start, left_id, word_cost) = (nodes[i].start, nodes[i].left_id, nodes[i].word_cost);
+            for &p in &ends[start] {
+                if best[p] == i64::MAX {
+                    continue;
+                }
+                let cost = best[p] + self.connection_cost(nodes[p].right_id, left_id) + word_cost;
+                if cost < best[i] {
+                    best[i] = cost;
+                    back[i] = p;
+                }
+            }
+        }
+
+        let mut result = Vec::new();
+        let mut cur = back[eos];
+        while cur != usize::MAX && cur != 0 {
+            result.push(nodes[cur].surface.clone());
+            cur = back[cur];
+        }
+        result.reverse();
+        result
+    }
+}
+
+pub struct PhraseMatcher {
+    tokenizer: Box<dyn Tokenizer>,
+    model_dir: String,
+    backend: VocabBackend,
+    vocab: Vocab,
+    inv_vocab: HashMap<usize, String>,
+    patterns: Patterns,
+}
+
+/// Storage strategy for the surface→ID vocabulary.
+///
+/// `HashMap` keeps the classic in-RAM map serialized with bincode — fine for
+/// the small models the crate shipped with. `Fst` builds a sorted, compressed
+/// finite-state transducer that is memory-mapped at load time, so a
+/// multi-million-word lexicon costs only the mmap rather than every key
+/// string kept resident on the heap.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VocabBackend {
+    HashMap,
+    Fst,
+}
+
+/// Runtime vocabulary lookup, abstracting over the chosen backend so the rest
+/// of the matcher resolves tokens the same way regardless of storage.
+enum Vocab {
+    Map(HashMap<String, usize>),
+    Fst(fst::Map<memmap2::Mmap>),
+}
+
+impl Vocab {
+    fn get(&self, token: &str) -> Option<usize> {
+        match self {
+            Vocab::Map(m) => m.get(token).copied(),
+            Vocab::Fst(m) => m.get(token).map(|v| v as usize),
+        }
+    }
+
+    fn len(&self) -> usize {
+        match self {
+            Vocab::Map(m) => m.len(),
+            Vocab::Fst(m) => m.len(),
+        }
+    }
+}
+
+/// Exact multi-pattern automaton keyed on vocabulary IDs.
+///
+/// The trie is stored as a sparse goto function `(node, token_id) -> node`;
+/// `fail[n]` is the Aho-Corasick failure link and `output[n]` holds the
+/// lengths of every pattern that ends at node `n` (including the patterns
+/// inherited through the fail chain), which is all we need to recover a
+/// `(start, end)` span from the position where the match terminates.
+#[derive(Serialize, Deserialize)]
+pub struct Patterns {
+    goto: HashMap<(usize, usize), usize>,
+    fail: Vec<usize>,
+    output: Vec<Vec<usize>>,
+}
+
+impl Patterns {
+    fn new() -> Self {
+        Patterns {
+            goto: HashMap::new(),
+            fail: vec![0],
+            output: vec![Vec::new()],
+        }
+    }
+}
+
+/// How overlapping candidate spans are reconciled into the final match set.
+///
+/// The variants mirror the semantics exposed by text searchers such as the
+/// `aho-corasick` crate: `Standard` is leftmost-first (non-overlapping,
+/// preferring the shortest span at each start), `LeftmostLongest` is
+/// non-overlapping but prefers the longest span, and `Overlapping` emits
+/// every candidate span.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MatchKind {
+    Standard,
+    LeftmostLongest,
+    Overlapping,
+}
+
+impl PhraseMatcher {
+    pub fn new(
+        model_dir: &str,
+        pattern_file: Option<&str>,
+        vocab_file: Option<&str>,
+        max_len: usize,
+        tokenizer: Box<dyn Tokenizer>,
+        backend: VocabBackend,
+    ) -> Result<Self, PhraseMatcherError> {
+        let mut matcher = PhraseMatcher {
+            tokenizer,
+            model_dir: model_dir.to_string(),
+            backend,
+            vocab: Vocab::Map(HashMap::new()),
+            inv_vocab: HashMap::<usize, String>::new(),
+            patterns: Patterns::new(),
+        };
+
+        if !Path::new(model_dir).exists() {
+            fs::create_dir_all(model_dir)?;
+        }
+
+        if let Some(pattern_file) = pattern_file {
+            if let Some(vocab_file) = vocab_file {
+                matcher.read_vocab(vocab_file)?;
+            } else {
+                matcher.build_vocab(pattern_file)?;
+            }
+            matcher.compile(pattern_file, max_len)?;
+        } else {
+            matcher.load_saved_data()?;
+        }
+
+        Ok(matcher)
+    }
+
+    fn read_vocab(&mut self, fname: &str) -> Result<(), PhraseMatcherError> {
+        println!("Reading vocab file...");
+        let mut wc = HashMap::<String, usize>::new();
+
+        let reader = io::BufReader::new(fs::File::open(fname)?);
+        for line in reader.lines() {
+            let line = line?;
+            let parts = self.tokenizer.tokenize(line.to_lowercase().trim());
+            if let Some(word) = parts.first() {
+                // Only advance the counter for genuinely new surfaces; a plain
+                // `insert(_, wc.len())` would re-number an existing key and let
+                // the next distinct word reuse that ID, breaking the bijection
+                // the ID-keyed automaton relies on.
+                let id = wc.len();
+                wc.entry(word.to_string()).or_insert(id);
+            }
+        }
+
+        let n_vocab = wc.len();
+        self.persist_vocab(&wc)?;
+
+        let mut sorted_wc: Vec<_> = wc.iter().map(|(k, &v)| (k.clone(), v)).collect();
+        sorted_wc.sort_by_key(|&(_, v)| Reverse(v));
+
+        for (idx, (word, _)) in sorted_wc.iter().enumerate() {
+            self.inv_vocab.insert(idx, word.clone());
+        }
+
+        self.vocab = Vocab::Map(wc);
+        println!("Vocab size: {}", n_vocab);
+        Ok(())
+    }
+
+    fn build_vocab(&mut self, fname: &str) -> Result<(), PhraseMatcherError> {
+        println!("Start building vocab...");
+        let mut counts = HashMap::<String, usize>::new();
+
+        let reader = io::BufReader::new(fs::File::open(fname)?);
+        for line in reader.lines() {
+            let line = line?;
+            for word in self.tokenizer.tokenize(line.to_lowercase().trim()) {
+                *counts.entry(word.to_string()).or_insert(0) += 1;
+            }
+        }
+
+        // Assign each surface a *unique* sequential ID, most frequent first.
+        // The automaton is keyed purely on these IDs, so the mapping must be
+        // a bijection — sharing one ID across equal-frequency words would let
+        // distinct token sequences collide and produce false matches.
+        let mut sorted_counts: Vec<(String, usize)> = counts.into_iter().collect();
+        sorted_counts.sort_by_key(|&(_, count)| Reverse(count));
+
+        let mut wc = HashMap::<String, usize>::new();
+        for (idx, (word, _)) in sorted_counts.into_iter().enumerate() {
+            self.inv_vocab.insert(idx, word.clone());
+            wc.insert(word, idx);
+        }
+
+        self.persist_vocab(&wc)?;
+        self.vocab = Vocab::Map(wc);
+        println!("Vocab size: {}", self.vocab.len());
+        Ok(())
+    }
+
+    /// Write the working vocabulary to `model_dir` in the format dictated by
+    /// the selected backend: a bincode `vocab.p` for `HashMap`, or a sorted,
+    /// mmap-able `vocab.fst` for `Fst`.
+    fn persist_vocab(&self, wc: &HashMap<String, usize>) -> Result<(), PhraseMatcherError> {
+        match self.backend {
+            VocabBackend::HashMap => {
+                let path = format!("{}/vocab.p", self.model_dir);
+                let file = fs::File::create(path)?;
+                bincode::serialize_into(file, wc)?;
+            }
+            VocabBackend::Fst => {
+                // The FST builder requires keys in lexicographic order.
+                let mut sorted: Vec<(&String, usize)> =
+                    wc.iter().map(|(k, &v)| (k, v)).collect();
+                sorted.sort_by_key(|&(k, _)| k);
+
+                let path = format!("{}/vocab.fst", self.model_dir);
+                let writer = io::BufWriter::new(fs::File::create(path)?);
+                let mut builder = fst::MapBuilder::new(writer)?;
+                for (word, id) in sorted {
+                    builder.insert(word, id as u64)?;
+                }
+                builder.finish()?;
+            }
+        }
+        Ok(())
+    }
+
+
+    fn compile(&mut self, fname: &str, max_len: usize) -> Result<(), PhraseMatcherError> {
+        println!("Start compiling patterns...");
+        self.patterns = Patterns::new();
+
+        // Build the goto trie. `children[n]` mirrors the goto edges leaving
+        // node `n` so the failure-link BFS below can enumerate them without
+        // scanning the whole goto map.
+        let mut children: Vec<Vec<(usize, usize)>> = vec![Vec::new()];
+
+        let reader = io::BufReader::new(fs::File::open(fname)?);
+        for (i, pat) in reader.lines().enumerate() {
+            if i % 100000 == 0 {
+                println!("Processing input patterns: {}", i);
+            }
+
+            let pat = pat?;
+            let p_arr: Vec<_> = pat.split_whitespace().collect();
+            let p_len = p_arr.len();
+
+            if p_len > max_len {
+                continue;
+            }
+
+            let mut p_ints = Vec::new();
+            for t in &p_arr {
+                if let Some(v) = self.vocab.get(t) {
+                    p_ints.push(v);
+                } else {
+                    p_ints.clear();
+                    break;
+                }
+            }
+
+            if p_ints.is_empty() {
+                continue;
+            }
+
+            let mut node = 0;
+            for &c in &p_ints {
+                node = match self.patterns.goto.get(&(node, c)) {
+                    Some(&next) => next,
+                    None => {
+                        let next = self.patterns.output.len();
+                        self.patterns.goto.insert((node, c), next);
+                        self.patterns.output.push(Vec::new());
+                        children.push(Vec::new());
+                        children[node].push((c, next));
+                        next
+                    }
+                };
+            }
+            self.patterns.output[node].push(p_len);
+        }
+
+        self.build_failure_links(&children);
+
+        let patterns_file = format!("{}/patterns.p", self.model_dir);
+        let patterns_file = fs::File::create(patterns_file)?;
+        bincode::serialize_into(patterns_file, &self.patterns)?;
+        Ok(())
+    }
+
+    /// BFS from the root wiring up each node's failure link and folding the
+    /// fail target's output set into the node's own, so a single lookup at
+    /// match time yields every pattern ending at the current position.
+    fn build_failure_links(&mut self, children: &[Vec<(usize, usize)>]) {
+        self.patterns.fail = vec![0; self.patterns.output.len()];
+
+        let mut queue = VecDeque::new();
+        for &(_, child) in &children[0] {
+            self.patterns.fail[child] = 0;
+            queue.push_back(child);
+        }
+
+        while let Some(node) = queue.pop_front() {
+            for &(c, child) in &children[node] {
+                queue.push_back(child);
+
+                let mut f = self.patterns.fail[node];
+                while f != 0 && !self.patterns.goto.contains_key(&(f, c)) {
+                    f = self.patterns.fail[f];
+                }
+                let target = match self.patterns.goto.get(&(f, c)) {
+                    Some(&t) if t != child => t,
+                    _ => 0,
+                };
+                self.patterns.fail[child] = target;
+
+                let mut inherited = self.patterns.output[target].clone();
+                self.patterns.output[child].append(&mut inherited);
+            }
+        }
+    }
+
+    pub fn match_phrase(&self, sentence: &str, kind: MatchKind) -> Vec<String> {
+        let tok = self.tokenizer.tokenize(sentence.trim());
+        let mut tok_ints = Vec::new();
+        for t in &tok {
+            // Tokens outside the vocabulary cannot be part of any pattern;
+            // a sentinel ID keeps them out of every goto edge so the walk
+            // simply falls back toward the root.
+            match self.vocab.get(t.as_str()) {
+                Some(v) => tok_ints.push(v),
+                None => tok_ints.push(usize::MAX),
+            }
+        }
+
+        // Single pass of the automaton over the token IDs: every output hit
+        // at position `i` closes a pattern of the recorded length, yielding an
+        // exact `(start, end)` span with no checksum false positives.
+        let mut candidates = HashSet::new();
+        let mut node = 0;
+        for (i, &c) in tok_ints.iter().enumerate() {
+            while node != 0 && !self.patterns.goto.contains_key(&(node, c)) {
+                node = self.patterns.fail[node];
+            }
+            node = self.patterns.goto.get(&(node, c)).copied().unwrap_or(0);
+
+            for &p_len in &self.patterns.output[node] {
+                candidates.insert((i + 1 - p_len, i));
+            }
+        }
+
+        let mut spans: Vec<(usize, usize)> = candidates.into_iter().collect();
+
+        // `Standard` and `LeftmostLongest` both emit non-overlapping matches
+        // via a left-to-right cursor; they differ only in which span wins at a
+        // given start. `Standard` is leftmost-first — the shortest span at each
+        // position, mirroring the default semantics of the `aho-corasick`
+        // crate — while `LeftmostLongest` prefers the longest. The tie-breaking
+        // length order is baked into the sort so the sweep stays a single pass.
+        let mut results = Vec::new();
+        match kind {
+            MatchKind::Standard => {
+                spans.sort_by(|&(ai, aj), &(bi, bj)| ai.cmp(&bi).then((aj - ai).cmp(&(bj - bi))));
+                let mut cursor = 0;
+                for &(i, j) in &spans {
+                    if i >= cursor {
+                        results.push(tok[i..=j].join(" "));
+                        cursor = j + 1;
+                    }
+                }
+            }
+            MatchKind::LeftmostLongest => {
+                spans.sort_by(|&(ai, aj), &(bi, bj)| ai.cmp(&bi).then((bj - bi).cmp(&(aj - ai))));
+                let mut cursor = 0;
+                for &(i, j) in &spans {
+                    if i >= cursor {
+                        results.push(tok[i..=j].join(" "));
+                        cursor = j + 1;
+                    }
+                }
+            }
+            MatchKind::Overlapping => {
+                spans.sort_by(|&(ai, aj), &(bi, bj)| ai.cmp(&bi).then((bj - bi).cmp(&(aj - ai))));
+                for &(i, j) in &spans {
+                    results.push(tok[i..=j].join(" "));
+                }
+            }
+        }
+
+        results
+    }
+
+    /// Match every line of `reader` lazily, yielding `(line_index, matches)`
+    /// for each record without buffering the whole input — the shape log- and
+    /// stream-processing matchers expose. Lines that fail to read are skipped.
+    pub fn match_stream<'a, R: BufRead + 'a>(
+        &'a self,
+        reader: R,
+        kind: MatchKind,
+    ) -> impl Iterator<Item = (usize, Vec<String>)> + 'a {
+        reader
+            .lines()
+            .enumerate()
+            .filter_map(move |(i, line)| line.ok().map(|line| (i, self.match_phrase(&line, kind))))
+    }
+
+    fn load_saved_data(&mut self) -> Result<(), PhraseMatcherError> {
+        match self.backend {
+            VocabBackend::HashMap => {
+                let vocab_file = format!("{}/vocab.p", self.model_dir);
+                let file = fs::File::open(vocab_file).map_err(|_| PhraseMatcherError::MissingVocab)?;
+                self.vocab = Vocab::Map(bincode::deserialize_from(file)?);
+            }
+            VocabBackend::Fst => {
+                let vocab_file = format!("{}/vocab.fst", self.model_dir);
+                let file = fs::File::open(vocab_file).map_err(|_| PhraseMatcherError::MissingVocab)?;
+                // Safety: the file is a model artifact produced by this crate
+                // and is only ever read through the mmap.
+                let mmap = unsafe { memmap2::Mmap::map(&file)? };
+                self.vocab = Vocab::Fst(fst::Map::new(mmap)?);
+            }
+        }
+
+        let patterns_file = format!("{}/patterns.p", self.model_dir);
+        let patterns_file = fs::File::open(patterns_file)?;
+        self.patterns = bincode::deserialize_from(patterns_file)?;
+        Ok(())
+    }
+}
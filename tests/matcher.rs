@@ -0,0 +1,162 @@
+use std::fs;
+use std::io::Cursor;
+use std::path::{Path, PathBuf};
+
+use phrasematcher_rs::{
+    LatticeTokenizer, MatchKind, PhraseMatcher, Tokenizer, VocabBackend, WhitespaceTokenizer,
+};
+
+/// A fresh, empty scratch directory unique to this test name.
+fn scratch(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("pm-test-{}-{}", std::process::id(), name));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn write_patterns(dir: &Path, lines: &[&str]) -> String {
+    let path = dir.join("patterns.txt");
+    fs::write(&path, lines.join("\n")).unwrap();
+    path.to_str().unwrap().to_string()
+}
+
+fn sorted(mut v: Vec<String>) -> Vec<String> {
+    v.sort();
+    v
+}
+
+fn build(dir: &Path, patterns: &[&str], backend: VocabBackend) -> PhraseMatcher {
+    let pattern_file = write_patterns(dir, patterns);
+    let tokenizer: Box<dyn Tokenizer> = Box::new(WhitespaceTokenizer);
+    PhraseMatcher::new(
+        dir.to_str().unwrap(),
+        Some(&pattern_file),
+        None,
+        10,
+        tokenizer,
+        backend,
+    )
+    .unwrap()
+}
+
+#[test]
+fn exact_automaton_has_no_checksum_false_positives() {
+    let dir = scratch("exact");
+    let matcher = build(&dir, &["alpha beta", "charlie delta"], VocabBackend::HashMap);
+
+    // A real pattern matches...
+    assert_eq!(
+        matcher.match_phrase("alpha beta", MatchKind::Standard),
+        vec!["alpha beta".to_string()]
+    );
+    // ...but a never-inserted cross-over sequence must not.
+    assert!(matcher
+        .match_phrase("alpha delta", MatchKind::Standard)
+        .is_empty());
+}
+
+#[test]
+fn read_vocab_keeps_ids_unique_with_repeated_keys() {
+    let dir = scratch("read-vocab");
+    // A repeated first token must not re-number an existing surface; each
+    // distinct word keeps a unique ID so the automaton stays exact.
+    let vocab_path = dir.join("vocab.txt");
+    fs::write(&vocab_path, "alpha\nalpha\nbeta\ncharlie\ndelta\n").unwrap();
+    let pattern_file = write_patterns(&dir, &["alpha beta", "charlie delta"]);
+
+    let tokenizer: Box<dyn Tokenizer> = Box::new(WhitespaceTokenizer);
+    let matcher = PhraseMatcher::new(
+        dir.to_str().unwrap(),
+        Some(&pattern_file),
+        Some(vocab_path.to_str().unwrap()),
+        10,
+        tokenizer,
+        VocabBackend::HashMap,
+    )
+    .unwrap();
+
+    assert_eq!(
+        matcher.match_phrase("alpha beta", MatchKind::Standard),
+        vec!["alpha beta".to_string()]
+    );
+    assert!(matcher
+        .match_phrase("alpha delta", MatchKind::Standard)
+        .is_empty());
+    // Were "alpha" and "beta" to share an ID, this reversal would match.
+    assert!(matcher
+        .match_phrase("beta alpha", MatchKind::Standard)
+        .is_empty());
+}
+
+#[test]
+fn match_kinds_resolve_overlaps_distinctly() {
+    let dir = scratch("kinds");
+    let matcher = build(&dir, &["a b", "b c", "a b c"], VocabBackend::HashMap);
+
+    assert_eq!(
+        matcher.match_phrase("a b c", MatchKind::Standard),
+        vec!["a b".to_string()]
+    );
+    assert_eq!(
+        matcher.match_phrase("a b c", MatchKind::LeftmostLongest),
+        vec!["a b c".to_string()]
+    );
+    assert_eq!(
+        sorted(matcher.match_phrase("a b c", MatchKind::Overlapping)),
+        sorted(vec![
+            "a b".to_string(),
+            "a b c".to_string(),
+            "b c".to_string(),
+        ])
+    );
+}
+
+#[test]
+fn match_stream_yields_per_line() {
+    let dir = scratch("stream");
+    let matcher = build(&dir, &["a b"], VocabBackend::HashMap);
+
+    let input = Cursor::new("a b\nc d\n");
+    let results: Vec<(usize, Vec<String>)> = matcher.match_stream(input, MatchKind::Standard).collect();
+    assert_eq!(results[0], (0, vec!["a b".to_string()]));
+    assert_eq!(results[1], (1, Vec::<String>::new()));
+}
+
+#[test]
+fn lattice_viterbi_picks_minimum_cost_segmentation() {
+    let dir = scratch("lattice");
+    let dict_path = dir.join("dict.csv");
+    fs::write(
+        &dict_path,
+        "東京,0,0,50\n都,0,0,100\n東,0,0,500\n京,0,0,500\n京都,0,0,900\n",
+    )
+    .unwrap();
+    let matrix_path = dir.join("matrix.def");
+    fs::write(&matrix_path, "1 1\n0 0 0\n").unwrap();
+
+    let tokenizer =
+        LatticeTokenizer::load(dict_path.to_str().unwrap(), matrix_path.to_str().unwrap()).unwrap();
+    // 東京(50)+都(100) = 150 beats 東(500)+京都(900) and 東+京+都.
+    assert_eq!(tokenizer.tokenize("東京都"), vec!["東京", "都"]);
+}
+
+#[test]
+fn fst_backend_round_trips_through_model_dir() {
+    let dir = scratch("fst");
+    // First pass compiles the model and writes vocab.fst + patterns.p.
+    build(&dir, &["alpha beta", "charlie delta"], VocabBackend::Fst);
+
+    // Second pass loads the saved model, resolving tokens via the mmap'd FST.
+    let tokenizer: Box<dyn Tokenizer> = Box::new(WhitespaceTokenizer);
+    let loaded =
+        PhraseMatcher::new(dir.to_str().unwrap(), None, None, 10, tokenizer, VocabBackend::Fst)
+            .unwrap();
+
+    assert_eq!(
+        loaded.match_phrase("alpha beta", MatchKind::Standard),
+        vec!["alpha beta".to_string()]
+    );
+    assert!(loaded
+        .match_phrase("alpha delta", MatchKind::Standard)
+        .is_empty());
+}